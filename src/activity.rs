@@ -0,0 +1,65 @@
+use chrono::Local;
+use futures::stream::{self, Stream};
+use std::sync::Arc;
+use tokio::sync::{Mutex, mpsc};
+
+/// Oldest entry is dropped once the log holds this many.
+pub const MAX_ENTRIES: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub fn label(self) -> &'static str {
+        match self {
+            Severity::Info => "INFO",
+            Severity::Success => "OK",
+            Severity::Warning => "WARN",
+            Severity::Error => "ERROR",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ActivityEntry {
+    pub timestamp: String,
+    pub severity: Severity,
+    pub text: String,
+}
+
+impl ActivityEntry {
+    pub fn new(severity: Severity, text: impl Into<String>) -> Self {
+        Self {
+            timestamp: Local::now().format("%H:%M:%S").to_string(),
+            severity,
+            text: text.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ActivityEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}: {}", self.timestamp, self.severity.label(), self.text)
+    }
+}
+
+pub type Sender = mpsc::UnboundedSender<ActivityEntry>;
+pub type SharedReceiver = Arc<Mutex<mpsc::UnboundedReceiver<ActivityEntry>>>;
+
+pub fn channel() -> (Sender, SharedReceiver) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    (tx, Arc::new(Mutex::new(rx)))
+}
+
+/// Adapts the shared receiver into a `Stream` so it can be wired into an `iced::Subscription`.
+pub fn stream(receiver: SharedReceiver) -> impl Stream<Item = ActivityEntry> {
+    stream::unfold(receiver, |receiver| async move {
+        let entry = receiver.lock().await.recv().await;
+        entry.map(|entry| (entry, receiver))
+    })
+}
@@ -0,0 +1,159 @@
+use async_trait::async_trait;
+use image::DynamicImage;
+use xcap::Monitor;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonitorInfo {
+    pub id: u32,
+    pub name: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl MonitorInfo {
+    /// Returns an empty list rather than erroring, so a missing display just leaves the picker empty.
+    pub fn enumerate() -> Vec<MonitorInfo> {
+        Monitor::all()
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .map(|(i, m)| MonitorInfo {
+                id: i as u32,
+                name: m.name(),
+                x: m.x(),
+                y: m.y(),
+                width: m.width(),
+                height: m.height(),
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for MonitorInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({}x{} @ {},{})",
+            self.name, self.width, self.height, self.x, self.y
+        )
+    }
+}
+
+/// Fractions (0.0-1.0) of the target monitor's dimensions, not pixels, so a region survives a
+/// resolution change between pick time and capture time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NormalizedRect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl NormalizedRect {
+    pub const FULL: NormalizedRect = NormalizedRect {
+        x: 0.0,
+        y: 0.0,
+        width: 1.0,
+        height: 1.0,
+    };
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CaptureMode {
+    FullMonitor,
+    Region,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CaptureTarget {
+    Monitor(u32),
+    Region { monitor: u32, rect: NormalizedRect },
+}
+
+impl CaptureTarget {
+    fn monitor_id(self) -> u32 {
+        match self {
+            CaptureTarget::Monitor(id) => id,
+            CaptureTarget::Region { monitor, .. } => monitor,
+        }
+    }
+}
+
+/// Lets the rest of the app be driven headless in tests, without a real display attached.
+#[async_trait]
+pub trait CaptureBackend: Send + Sync {
+    async fn capture(&self, target: CaptureTarget) -> Result<DynamicImage, String>;
+}
+
+pub struct XcapBackend;
+
+#[async_trait]
+impl CaptureBackend for XcapBackend {
+    async fn capture(&self, target: CaptureTarget) -> Result<DynamicImage, String> {
+        tokio::task::spawn_blocking(move || {
+            let monitors = Monitor::all().map_err(|e| e.to_string())?;
+            let id = target.monitor_id();
+            let monitor = monitors
+                .get(id as usize)
+                .or_else(|| monitors.iter().find(|m| m.x() == 0 && m.y() == 0))
+                .or_else(|| monitors.first())
+                .ok_or("No display found.")?;
+
+            let image = monitor.capture_image().map_err(|e| e.to_string())?;
+            let image = DynamicImage::ImageRgba8(image);
+
+            Ok(match target {
+                CaptureTarget::Monitor(_) => image,
+                CaptureTarget::Region { rect, .. } => crop_to(&image, rect),
+            })
+        })
+        .await
+        .map_err(|e| e.to_string())?
+    }
+}
+
+pub struct FakeBackend {
+    pub color: [u8; 3],
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Default for FakeBackend {
+    fn default() -> Self {
+        Self {
+            color: [120, 120, 200],
+            width: 320,
+            height: 180,
+        }
+    }
+}
+
+#[async_trait]
+impl CaptureBackend for FakeBackend {
+    async fn capture(&self, target: CaptureTarget) -> Result<DynamicImage, String> {
+        let mut image = image::RgbImage::new(self.width, self.height);
+        for pixel in image.pixels_mut() {
+            *pixel = image::Rgb(self.color);
+        }
+        let image = DynamicImage::ImageRgb8(image);
+
+        Ok(match target {
+            CaptureTarget::Monitor(_) => image,
+            CaptureTarget::Region { rect, .. } => crop_to(&image, rect),
+        })
+    }
+}
+
+fn crop_to(image: &DynamicImage, rect: NormalizedRect) -> DynamicImage {
+    let width = image.width();
+    let height = image.height();
+
+    let x = (rect.x * width as f32).round().clamp(0.0, width as f32) as u32;
+    let y = (rect.y * height as f32).round().clamp(0.0, height as f32) as u32;
+    let crop_width = ((rect.width * width as f32).round() as u32).clamp(1, width.saturating_sub(x).max(1));
+    let crop_height = ((rect.height * height as f32).round() as u32).clamp(1, height.saturating_sub(y).max(1));
+
+    image.crop_imm(x, y, crop_width, crop_height)
+}
@@ -1,12 +1,23 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
+mod activity;
+mod capture;
+mod region;
+mod settings;
+mod update_check;
+
+use activity::{ActivityEntry, Severity};
 use calamine::{Reader, Xlsx, open_workbook};
+use capture::{CaptureBackend, CaptureMode, CaptureTarget, MonitorInfo, NormalizedRect, XcapBackend};
 use iced::keyboard;
-use iced::widget::{button, column, container, image, row, scrollable, text};
+use iced::widget::{
+    button, checkbox, column, container, image, pick_list, row, scrollable, text, text_input,
+};
 use iced::{Border, Element, Event, Length, Settings, Size, Task, Theme, window};
+use settings::{ImageFormat, Settings as AppSettings};
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
-use xcap::Monitor;
 
 pub fn main() -> iced::Result {
     MyApp::run(Settings::default())
@@ -17,6 +28,7 @@ enum Message {
     OpenFile,
     FileSelected(Option<PathBuf>),
     SelectItem(usize),
+    SearchChanged(String),
     StartCapture,
     TickCapture,
     CaptureFinished(Result<PathBuf, String>),
@@ -25,12 +37,33 @@ enum Message {
     SetView(View),
     KeyPressed(keyboard::Key),
     Init(window::Id),
+    SettingsDelayChanged(String),
+    SettingsFormatChanged(ImageFormat),
+    SettingsTemplateChanged(String),
+    SettingsOutputDirChanged(String),
+    SettingsBrowseOutputDir,
+    SettingsOutputDirSelected(Option<PathBuf>),
+    SettingsSaved(Result<(), String>),
+    MonitorSelected(MonitorInfo),
+    CaptureModeChanged(CaptureMode),
+    RegionEvent(region::Event),
+    ActivityLogged(ActivityEntry),
+    CopyLog,
+    ExportLog,
+    ExportLogPathSelected(Option<PathBuf>),
+    ExportLogWritten(Result<(), String>),
+    UpdateAvailable(String, String),
+    DismissUpdate(String),
+    OpenReleasePage(String),
+    SettingsUpdateCheckToggled(bool),
+    NoOp,
 }
 
 #[derive(PartialEq, Debug, Clone, Copy)]
 enum View {
     Main,
     Info,
+    Settings,
 }
 
 #[derive(PartialEq, Clone)]
@@ -50,26 +83,93 @@ struct MyApp {
     last_capture_path: Option<PathBuf>,
     items: Vec<TestItem>,
     selected_index: Option<usize>,
-    status_message: String,
+    query: String,
+    activity_log: Vec<ActivityEntry>,
+    activity_tx: activity::Sender,
+    activity_rx: activity::SharedReceiver,
+    settings_tx: settings::Sender,
+    settings_rx: settings::SharedReceiver,
     state: AppState,
     current_view: View,
+    settings: AppSettings,
+    backend: Arc<dyn CaptureBackend>,
+    monitors: Vec<MonitorInfo>,
+    selected_monitor: Option<MonitorInfo>,
+    capture_mode: CaptureMode,
+    region_picker: region::Picker,
+    update_banner: Option<(String, String)>,
 }
 
 impl MyApp {
     fn new() -> (Self, Task<Message>) {
-        (
-            Self {
-                window_id: None,
-                excel_path: None,
-                last_capture_path: None,
-                items: Vec::new(),
-                selected_index: None,
-                status_message: String::from("System ready. Load an Excel file to begin."),
-                state: AppState::Idle,
-                current_view: View::Main,
-            },
-            Task::none(),
-        )
+        Self::with_backend(Self::default_backend())
+    }
+
+    /// Real backend in production, a deterministic fake under `cargo test`.
+    fn default_backend() -> Arc<dyn CaptureBackend> {
+        #[cfg(test)]
+        {
+            Arc::new(capture::FakeBackend::default())
+        }
+        #[cfg(not(test))]
+        {
+            Arc::new(XcapBackend)
+        }
+    }
+
+    fn with_backend(backend: Arc<dyn CaptureBackend>) -> (Self, Task<Message>) {
+        let monitors = MonitorInfo::enumerate();
+        let selected_monitor = monitors.first().cloned();
+        let (activity_tx, activity_rx) = activity::channel();
+        let (settings_tx, settings_rx) = settings::channel();
+
+        let mut app = Self {
+            window_id: None,
+            excel_path: None,
+            last_capture_path: None,
+            items: Vec::new(),
+            selected_index: None,
+            query: String::new(),
+            activity_log: Vec::new(),
+            activity_tx,
+            activity_rx,
+            settings_tx,
+            settings_rx,
+            state: AppState::Idle,
+            current_view: View::Main,
+            settings: AppSettings::load(),
+            backend,
+            monitors,
+            selected_monitor,
+            capture_mode: CaptureMode::FullMonitor,
+            region_picker: region::Picker::default(),
+            update_banner: None,
+        };
+        app.push_activity(Severity::Info, "System ready. Load an Excel file to begin.");
+
+        let update_task = app.check_for_update_task();
+        (app, update_task)
+    }
+
+    /// No-ops under `cargo test` so constructing `MyApp` never makes a network call.
+    #[cfg(not(test))]
+    fn check_for_update_task(&self) -> Task<Message> {
+        if !self.settings.check_for_updates {
+            return Task::none();
+        }
+
+        let dismissed = self.settings.dismissed_update_version.clone();
+        Task::perform(update_check::check_for_update(), move |result| match result {
+            Some((version, url)) if Some(&version) != dismissed.as_ref() => {
+                Message::UpdateAvailable(version, url)
+            }
+            _ => Message::NoOp,
+        })
+    }
+
+    #[cfg(test)]
+    fn check_for_update_task(&self) -> Task<Message> {
+        Task::none()
     }
 
     fn update(&mut self, message: Message) -> Task<Message> {
@@ -89,24 +189,32 @@ impl MyApp {
             Message::StartCapture => {
                 if let Some(id) = self.window_id {
                     self.state = AppState::Waiting;
-                    self.status_message = String::from("Action: Minimizing and capturing...");
+                    self.push_activity(Severity::Info, "Minimizing and capturing...");
+                    let delay_ms = self.settings.capture_delay_ms;
                     return Task::batch(vec![
                         window::minimize(id, true),
                         Task::perform(
-                            async { tokio::time::sleep(Duration::from_millis(1500)).await },
+                            async move { tokio::time::sleep(Duration::from_millis(delay_ms)).await },
                             |_| Message::TickCapture,
                         ),
                     ]);
                 } else {
-                    self.status_message = String::from("Error: Window ID missing.");
+                    self.push_activity(Severity::Error, "Window ID missing.");
                 }
             }
             Message::TickCapture => {
                 if let (Some(idx), Some(path)) = (self.selected_index, &self.excel_path) {
                     let code = self.items[idx].code.clone();
                     let path = path.clone();
+                    let settings = self.settings.clone();
+                    let backend = self.backend.clone();
+                    let target = self.capture_target();
+                    let activity_tx = self.activity_tx.clone();
                     return Task::perform(
-                        async move { Self::async_capture(path, code).await },
+                        async move {
+                            Self::async_capture(path, code, settings, backend, target, activity_tx)
+                                .await
+                        },
                         Message::CaptureFinished,
                     );
                 }
@@ -115,11 +223,13 @@ impl MyApp {
                 self.state = AppState::Idle;
                 match result {
                     Ok(path) => {
-                        self.status_message =
-                            format!("File saved: {:?}", path.file_name().unwrap());
+                        self.push_activity(
+                            Severity::Success,
+                            format!("File saved: {:?}", path.file_name().unwrap()),
+                        );
                         self.last_capture_path = Some(path);
                     }
-                    Err(e) => self.status_message = format!("Error: {}", e),
+                    Err(e) => self.push_activity(Severity::Error, format!("Error: {}", e)),
                 }
                 if let Some(id) = self.window_id {
                     return window::minimize(id, false);
@@ -146,6 +256,7 @@ impl MyApp {
             }
             Message::FileSelected(Some(path)) => self.load_excel(path),
             Message::SelectItem(index) => self.selected_index = Some(index),
+            Message::SearchChanged(query) => self.query = query,
             Message::OpenFolder => {
                 if let Some(ref path) = self.excel_path {
                     if let Some(dir) = path.parent() {
@@ -157,11 +268,134 @@ impl MyApp {
                 }
             }
             Message::SetView(v) => self.current_view = v,
+            Message::SettingsDelayChanged(raw) => {
+                if let Ok(ms) = raw.parse() {
+                    self.settings.capture_delay_ms = ms;
+                    return self.save_settings();
+                }
+            }
+            Message::SettingsFormatChanged(format) => {
+                self.settings.image_format = format;
+                return self.save_settings();
+            }
+            Message::SettingsTemplateChanged(template) => {
+                self.settings.filename_template = template;
+                return self.save_settings();
+            }
+            Message::SettingsOutputDirChanged(raw) => {
+                self.settings.output_dir = if raw.is_empty() {
+                    None
+                } else {
+                    Some(PathBuf::from(raw))
+                };
+                return self.save_settings();
+            }
+            Message::SettingsBrowseOutputDir => {
+                return Task::perform(
+                    async { rfd::FileDialog::new().pick_folder() },
+                    Message::SettingsOutputDirSelected,
+                );
+            }
+            Message::SettingsOutputDirSelected(Some(dir)) => {
+                self.settings.output_dir = Some(dir);
+                return self.save_settings();
+            }
+            Message::SettingsOutputDirSelected(None) => {}
+            Message::SettingsSaved(Err(e)) => {
+                self.push_activity(Severity::Error, format!("Error saving settings: {}", e));
+            }
+            Message::SettingsSaved(Ok(())) => {}
+            Message::MonitorSelected(monitor) => self.selected_monitor = Some(monitor),
+            Message::CaptureModeChanged(mode) => self.capture_mode = mode,
+            Message::RegionEvent(region::Event::Changed(rect)) => {
+                self.region_picker.rect = Some(rect);
+            }
+            Message::ActivityLogged(entry) => self.push_activity_entry(entry),
+            Message::CopyLog => return iced::clipboard::write(self.activity_log_text()),
+            Message::ExportLog => {
+                return Task::perform(
+                    async {
+                        rfd::FileDialog::new()
+                            .set_file_name("hypergrab-activity-log.txt")
+                            .save_file()
+                    },
+                    Message::ExportLogPathSelected,
+                );
+            }
+            Message::ExportLogPathSelected(Some(path)) => {
+                let contents = self.activity_log_text();
+                return Task::perform(
+                    async move { std::fs::write(path, contents).map_err(|e| e.to_string()) },
+                    Message::ExportLogWritten,
+                );
+            }
+            Message::ExportLogPathSelected(None) => {}
+            Message::ExportLogWritten(Err(e)) => {
+                self.push_activity(Severity::Error, format!("Error exporting log: {}", e));
+            }
+            Message::ExportLogWritten(Ok(())) => {
+                self.push_activity(Severity::Success, "Activity log exported.");
+            }
+            Message::UpdateAvailable(version, url) => {
+                self.push_activity(Severity::Info, format!("Update available: v{}", version));
+                self.update_banner = Some((version, url));
+            }
+            Message::DismissUpdate(version) => {
+                self.update_banner = None;
+                self.settings.dismissed_update_version = Some(version);
+                return self.save_settings();
+            }
+            Message::OpenReleasePage(url) => {
+                #[cfg(target_os = "windows")]
+                let _ = std::process::Command::new("explorer").arg(&url).spawn();
+                #[cfg(target_os = "linux")]
+                let _ = std::process::Command::new("xdg-open").arg(&url).spawn();
+            }
+            Message::SettingsUpdateCheckToggled(enabled) => {
+                self.settings.check_for_updates = enabled;
+                return self.save_settings();
+            }
+            Message::NoOp => {}
             _ => {}
         }
         Task::none()
     }
 
+    fn capture_target(&self) -> CaptureTarget {
+        let monitor_id = self.selected_monitor.as_ref().map(|m| m.id).unwrap_or(0);
+        match self.capture_mode {
+            CaptureMode::FullMonitor => CaptureTarget::Monitor(monitor_id),
+            CaptureMode::Region => CaptureTarget::Region {
+                monitor: monitor_id,
+                rect: self.region_picker.rect.unwrap_or(NormalizedRect::FULL),
+            },
+        }
+    }
+
+    fn save_settings(&self) -> Task<Message> {
+        let _ = self.settings_tx.send(self.settings.clone());
+        Task::none()
+    }
+
+    fn push_activity(&mut self, severity: Severity, text: impl Into<String>) {
+        self.push_activity_entry(ActivityEntry::new(severity, text));
+    }
+
+    fn push_activity_entry(&mut self, entry: ActivityEntry) {
+        if self.activity_log.len() >= activity::MAX_ENTRIES {
+            self.activity_log.remove(0);
+        }
+        self.activity_log.push(entry);
+    }
+
+    fn activity_log_text(&self) -> String {
+        self.activity_log
+            .iter()
+            .map(|entry| entry.to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     fn view(&self) -> Element<'_, Message> {
         let nav = container(
             row![
@@ -171,16 +405,47 @@ impl MyApp {
                 button(text("SYSTEM INFO").size(12))
                     .on_press(Message::SetView(View::Info))
                     .padding([8, 20]),
+                button(text("SETTINGS").size(12))
+                    .on_press(Message::SetView(View::Settings))
+                    .padding([8, 20]),
             ]
             .spacing(10),
         )
         .width(Length::Fill);
 
+        let update_banner = self.update_banner.clone().map(|(version, url)| {
+            container(
+                row![
+                    text(format!("A new version is available: v{}", version)).size(12),
+                    button(text("View Release").size(12))
+                        .on_press(Message::OpenReleasePage(url))
+                        .padding([6, 12])
+                        .style(button::secondary),
+                    button(text("Dismiss").size(12))
+                        .on_press(Message::DismissUpdate(version))
+                        .padding([6, 12])
+                        .style(button::secondary),
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center),
+            )
+            .width(Length::Fill)
+            .padding(10)
+            .style(|theme: &Theme| {
+                let palette = theme.extended_palette();
+                container::Style {
+                    background: Some(palette.primary.weak.color.into()),
+                    ..Default::default()
+                }
+            })
+        });
+
         let content: Element<'_, Message> = match self.current_view {
             View::Main => {
                 let mut list_col = column![].spacing(2).width(Length::Fill);
 
-                for (i, item) in self.items.iter().enumerate() {
+                for (i, _score) in self.filtered_items() {
+                    let item = &self.items[i];
                     let is_selected = self.selected_index == Some(i);
                     list_col = list_col.push(
                         button(
@@ -201,6 +466,39 @@ impl MyApp {
                     );
                 }
 
+                let search_box = text_input("Filter test codes...", &self.query)
+                    .on_input(Message::SearchChanged)
+                    .padding(10)
+                    .size(14);
+
+                let monitor_picker = row![
+                    text("Monitor:").size(12),
+                    pick_list(
+                        self.monitors.clone(),
+                        self.selected_monitor.clone(),
+                        Message::MonitorSelected,
+                    )
+                    .text_size(12),
+                    button(text("Full monitor").size(12))
+                        .on_press(Message::CaptureModeChanged(CaptureMode::FullMonitor))
+                        .padding([6, 12])
+                        .style(if self.capture_mode == CaptureMode::FullMonitor {
+                            button::primary
+                        } else {
+                            button::secondary
+                        }),
+                    button(text("Region").size(12))
+                        .on_press(Message::CaptureModeChanged(CaptureMode::Region))
+                        .padding([6, 12])
+                        .style(if self.capture_mode == CaptureMode::Region {
+                            button::primary
+                        } else {
+                            button::secondary
+                        }),
+                ]
+                .spacing(10)
+                .align_y(iced::Alignment::Center);
+
                 let scroll_list = container(scrollable(list_col))
                     .height(300)
                     .padding(5)
@@ -227,10 +525,22 @@ impl MyApp {
                             .padding([10, 20]),
                     ]
                     .spacing(10),
+                    monitor_picker,
+                    search_box,
                     scroll_list,
                 ]
                 .spacing(20);
 
+                if self.capture_mode == CaptureMode::Region {
+                    main_view_col = main_view_col.push(
+                        column![
+                            text("Drag to select the region to capture:").size(11),
+                            region::view(&self.region_picker).map(Message::RegionEvent),
+                        ]
+                        .spacing(5),
+                    );
+                }
+
                 if let Some(ref path) = self.last_capture_path {
                     main_view_col = main_view_col.push(
                         column![
@@ -268,7 +578,7 @@ impl MyApp {
                     .size(12),
                     text("\n").size(16),
                     text("Architecture: Rust + Iced 0.13").size(16),
-                    text("Version: 0.99.0-STABLE").size(14),
+                    text(format!("Version: {}", update_check::CURRENT_VERSION)).size(14),
                     text("Platform: Windows & Linux compatible").size(14),
                     text("\n").size(16),
                     text("https://github.com/danico-oss/hypergrab")
@@ -286,17 +596,124 @@ impl MyApp {
             )
             .padding(40)
             .into(),
+            View::Settings => {
+                let mut format_row = row![].spacing(10);
+                for format in ImageFormat::ALL {
+                    let is_selected = self.settings.image_format == format;
+                    format_row = format_row.push(
+                        button(text(format.to_string()).size(12))
+                            .on_press(Message::SettingsFormatChanged(format))
+                            .padding([6, 14])
+                            .style(if is_selected {
+                                button::primary
+                            } else {
+                                button::secondary
+                            }),
+                    );
+                }
+
+                container(
+                    column![
+                        text("Capture Settings").size(20),
+                        column![
+                            text("Minimize delay (ms) before the screenshot is taken:").size(12),
+                            text_input("1500", &self.settings.capture_delay_ms.to_string())
+                                .on_input(Message::SettingsDelayChanged)
+                                .padding(8),
+                        ]
+                        .spacing(5),
+                        column![text("Image format:").size(12), format_row].spacing(5),
+                        column![
+                            text("Filename template (\"{code}\" is replaced with the test code):")
+                                .size(12),
+                            text_input("{code}", &self.settings.filename_template)
+                                .on_input(Message::SettingsTemplateChanged)
+                                .padding(8),
+                        ]
+                        .spacing(5),
+                        column![
+                            text("Output directory (blank = next to the Excel file):").size(12),
+                            row![
+                                text_input(
+                                    "",
+                                    self.settings
+                                        .output_dir
+                                        .as_ref()
+                                        .and_then(|p| p.to_str())
+                                        .unwrap_or("")
+                                )
+                                .on_input(Message::SettingsOutputDirChanged)
+                                .padding(8),
+                                button(text("Browse").size(12))
+                                    .on_press(Message::SettingsBrowseOutputDir)
+                                    .padding([8, 14]),
+                            ]
+                            .spacing(10),
+                        ]
+                        .spacing(5),
+                        checkbox("Check for updates on startup", self.settings.check_for_updates)
+                            .on_toggle(Message::SettingsUpdateCheckToggled),
+                    ]
+                    .spacing(20),
+                )
+                .padding(40)
+                .into()
+            }
         };
 
-        let mut main_column = column![nav, content].spacing(25);
+        let mut main_column = column![nav].spacing(25);
+        if let Some(banner) = update_banner {
+            main_column = main_column.push(banner);
+        }
+        main_column = main_column.push(content);
 
         if self.current_view == View::Main {
+            let mut log_col = column![].spacing(2).width(Length::Fill);
+            for entry in &self.activity_log {
+                log_col = log_col.push(
+                    row![
+                        text(format!("[{}]", entry.timestamp)).size(11),
+                        text(entry.severity.label()).size(11),
+                        text(entry.text.clone()).size(11),
+                    ]
+                    .spacing(8),
+                );
+            }
+
+            let log_panel = container(scrollable(log_col).height(Length::Fixed(90.0)))
+                .width(Length::Fill)
+                .padding(8)
+                .style(|theme: &Theme| {
+                    let palette = theme.extended_palette();
+                    container::Style {
+                        background: Some(palette.background.weak.color.into()),
+                        border: Border {
+                            color: palette.background.strong.color,
+                            width: 1.0,
+                            radius: 4.0.into(),
+                        },
+                        ..Default::default()
+                    }
+                });
+
             let status_footer = container(
-                row![
-                    text("STATUS:").size(12),
-                    text(self.status_message.clone()).size(12),
+                column![
+                    row![
+                        text("ACTIVITY LOG").size(12),
+                        button(text("Copy").size(11))
+                            .on_press(Message::CopyLog)
+                            .padding([4, 10])
+                            .style(button::secondary),
+                        button(text("Export .txt").size(11))
+                            .on_press(Message::ExportLog)
+                            .padding([4, 10])
+                            .style(button::secondary),
+                    ]
+                    .spacing(10)
+                    .align_y(iced::Alignment::Center),
+                    log_panel,
                 ]
-                .spacing(10),
+                .spacing(8),
             )
             .width(Length::Fill)
             .padding(12)
@@ -315,12 +732,26 @@ impl MyApp {
     }
 
     fn subscription(&self) -> iced::Subscription<Message> {
-        iced::event::listen_with(|event, _status, id| match event {
+        let events = iced::event::listen_with(|event, _status, id| match event {
             Event::Keyboard(iced::keyboard::Event::KeyPressed { key, .. }) => {
                 Some(Message::KeyPressed(key))
             }
             _ => Some(Message::Init(id)),
-        })
+        });
+
+        let activity_log = iced::Subscription::run_with_id(
+            "activity-log",
+            activity::stream(self.activity_rx.clone()),
+        )
+        .map(Message::ActivityLogged);
+
+        let settings_writer = iced::Subscription::run_with_id(
+            "settings-writer",
+            settings::stream(self.settings_rx.clone()),
+        )
+        .map(Message::SettingsSaved);
+
+        iced::Subscription::batch(vec![events, activity_log, settings_writer])
     }
 
     fn run(_settings: Settings) -> iced::Result {
@@ -359,35 +790,298 @@ impl MyApp {
                     })
                     .collect();
                 self.excel_path = Some(path);
-                self.status_message = format!("Loaded {} records.", self.items.len());
+                self.push_activity(Severity::Success, format!("Loaded {} records.", self.items.len()));
             }
         }
     }
 
-    async fn async_capture(excel_path: PathBuf, item_code: String) -> Result<PathBuf, String> {
-        let dir = excel_path.parent().unwrap_or(&excel_path).to_path_buf();
+    fn filtered_items(&self) -> Vec<(usize, i32)> {
+        if self.query.is_empty() {
+            return (0..self.items.len()).map(|i| (i, 0)).collect();
+        }
+
+        let query = self.query.to_lowercase();
+        let mut matches: Vec<(usize, i32)> = self
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| {
+                let candidate = format!("{} {}", item.code, item.description).to_lowercase();
+                fuzzy_score(&candidate, &query).map(|score| (i, score))
+            })
+            .collect();
+
+        matches.sort_by(|a, b| b.1.cmp(&a.1));
+        matches
+    }
+
+    async fn async_capture(
+        excel_path: PathBuf,
+        item_code: String,
+        settings: AppSettings,
+        backend: Arc<dyn CaptureBackend>,
+        target: CaptureTarget,
+        activity_tx: activity::Sender,
+    ) -> Result<PathBuf, String> {
+        let dir = settings
+            .output_dir
+            .clone()
+            .unwrap_or_else(|| excel_path.parent().unwrap_or(&excel_path).to_path_buf());
         let safe_name = item_code.replace(|c: char| !c.is_alphanumeric(), "_");
-        let mut final_path = dir.join(format!("{}.png", safe_name));
+        let ext = settings.image_format.extension();
+        let base_name = settings.filename_template.replace("{code}", &safe_name);
+        let mut final_path = dir.join(format!("{}.{}", base_name, ext));
         let mut counter = 1;
 
         while final_path.exists() {
-            final_path = dir.join(format!("{}_{}.png", safe_name, counter));
+            final_path = dir.join(format!("{}_{}.{}", base_name, counter, ext));
             counter += 1;
         }
 
-        let path_for_thread = final_path.clone();
-        tokio::task::spawn_blocking(move || {
-            let monitors = Monitor::all().map_err(|e| e.to_string())?;
-            let monitor = monitors
-                .iter()
-                .find(|m| m.x() == 0 && m.y() == 0)
-                .unwrap_or(monitors.first().ok_or("No display found.")?);
+        let _ = activity_tx.send(ActivityEntry::new(Severity::Info, "Capturing screen..."));
+        let image = backend.capture(target).await?;
 
-            let image = monitor.capture_image().map_err(|e| e.to_string())?;
-            image.save(&path_for_thread).map_err(|e| e.to_string())?;
-            Ok(path_for_thread)
-        })
+        let _ = activity_tx.send(ActivityEntry::new(
+            Severity::Info,
+            format!("Saving to {:?}...", final_path.file_name().unwrap_or_default()),
+        ));
+        image.save(&final_path).map_err(|e| e.to_string())?;
+        Ok(final_path)
+    }
+}
+
+/// Greedy subsequence match; `None` if `query` isn't fully matched, else a score rewarding
+/// consecutive runs and word-boundary hits.
+fn fuzzy_score(candidate: &str, query: &str) -> Option<i32> {
+    const MATCH_POINTS: i32 = 1;
+    const CONSECUTIVE_BONUS: i32 = 5;
+    const WORD_BOUNDARY_BONUS: i32 = 10;
+
+    let candidate: Vec<char> = candidate.chars().collect();
+    let mut query_chars = query.chars();
+    let Some(mut needle) = query_chars.next() else {
+        return Some(0);
+    };
+
+    let mut score = 0;
+    let mut prev_matched_index: Option<usize> = None;
+
+    for (i, &c) in candidate.iter().enumerate() {
+        if c != needle {
+            continue;
+        }
+
+        score += MATCH_POINTS;
+
+        let is_consecutive = prev_matched_index == Some(i.wrapping_sub(1)) && i > 0;
+        if is_consecutive {
+            score += CONSECUTIVE_BONUS;
+        }
+
+        let at_word_boundary = i == 0
+            || candidate[i - 1] == '_'
+            || !candidate[i - 1].is_alphanumeric();
+        if at_word_boundary {
+            score += WORD_BOUNDARY_BONUS;
+        }
+
+        prev_matched_index = Some(i);
+
+        match query_chars.next() {
+            Some(next) => needle = next,
+            None => return Some(score),
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use capture::FakeBackend;
+    use image::DynamicImage;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    fn temp_dir(label: &str) -> PathBuf {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = std::env::temp_dir().join(format!(
+            "hypergrab-test-{}-{}-{}",
+            label,
+            std::process::id(),
+            n
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn settings_with_output_dir(dir: PathBuf) -> AppSettings {
+        AppSettings {
+            output_dir: Some(dir),
+            ..AppSettings::default()
+        }
+    }
+
+    struct FailingBackend;
+
+    #[async_trait::async_trait]
+    impl CaptureBackend for FailingBackend {
+        async fn capture(&self, _target: CaptureTarget) -> Result<DynamicImage, String> {
+            Err("no display found".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn async_capture_writes_the_expected_file() {
+        let dir = temp_dir("basic");
+        let settings = settings_with_output_dir(dir.clone());
+        let backend: Arc<dyn CaptureBackend> = Arc::new(FakeBackend::default());
+        let (tx, _rx) = activity::channel();
+
+        let path = MyApp::async_capture(
+            dir.join("sheet.xlsx"),
+            "TC-001".to_string(),
+            settings,
+            backend,
+            CaptureTarget::Monitor(0),
+            tx,
+        )
         .await
-        .map_err(|e| e.to_string())?
+        .expect("capture should succeed");
+
+        assert_eq!(path, dir.join("TC-001.png"));
+        assert!(path.exists());
+    }
+
+    #[tokio::test]
+    async fn async_capture_deduplicates_filenames() {
+        let dir = temp_dir("dedup");
+        let excel_path = dir.join("sheet.xlsx");
+        let settings = settings_with_output_dir(dir.clone());
+        let backend: Arc<dyn CaptureBackend> = Arc::new(FakeBackend::default());
+
+        let (tx, _rx) = activity::channel();
+        let first = MyApp::async_capture(
+            excel_path.clone(),
+            "TC-001".to_string(),
+            settings.clone(),
+            backend.clone(),
+            CaptureTarget::Monitor(0),
+            tx,
+        )
+        .await
+        .unwrap();
+
+        let (tx, _rx) = activity::channel();
+        let second = MyApp::async_capture(
+            excel_path,
+            "TC-001".to_string(),
+            settings,
+            backend,
+            CaptureTarget::Monitor(0),
+            tx,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(first, dir.join("TC_001.png"));
+        assert_eq!(second, dir.join("TC_001_1.png"));
+    }
+
+    #[tokio::test]
+    async fn async_capture_surfaces_backend_errors() {
+        let dir = temp_dir("error");
+        let settings = settings_with_output_dir(dir.clone());
+        let backend: Arc<dyn CaptureBackend> = Arc::new(FailingBackend);
+        let (tx, _rx) = activity::channel();
+
+        let result = MyApp::async_capture(
+            dir.join("sheet.xlsx"),
+            "TC-001".to_string(),
+            settings,
+            backend,
+            CaptureTarget::Monitor(0),
+            tx,
+        )
+        .await;
+
+        assert_eq!(result, Err("no display found".to_string()));
+    }
+
+    #[test]
+    fn start_capture_transitions_to_waiting_and_logs() {
+        let (mut app, _task) = MyApp::new();
+        app.window_id = Some(window::Id::unique());
+        app.excel_path = Some(PathBuf::from("sheet.xlsx"));
+        app.items.push(TestItem {
+            code: "TC-001".into(),
+            description: "desc".into(),
+        });
+        app.selected_index = Some(0);
+
+        app.update(Message::StartCapture);
+
+        assert_eq!(app.state, AppState::Waiting);
+        assert!(app.activity_log.iter().any(|e| e.text.contains("Minimizing")));
+    }
+
+    #[test]
+    fn capture_finished_ok_records_success_and_resets_state() {
+        let (mut app, _task) = MyApp::new();
+        app.state = AppState::Waiting;
+
+        app.update(Message::CaptureFinished(Ok(PathBuf::from("/tmp/TC-001.png"))));
+
+        assert_eq!(app.state, AppState::Idle);
+        assert_eq!(app.last_capture_path, Some(PathBuf::from("/tmp/TC-001.png")));
+        assert!(
+            app.activity_log
+                .iter()
+                .any(|e| e.severity == Severity::Success)
+        );
+    }
+
+    #[test]
+    fn capture_finished_err_records_error() {
+        let (mut app, _task) = MyApp::new();
+        app.state = AppState::Waiting;
+
+        app.update(Message::CaptureFinished(Err("boom".to_string())));
+
+        assert_eq!(app.state, AppState::Idle);
+        assert!(
+            app.activity_log
+                .iter()
+                .any(|e| e.severity == Severity::Error)
+        );
+    }
+
+    #[test]
+    fn fuzzy_score_rejects_out_of_order_subsequence() {
+        assert_eq!(fuzzy_score("abc", "ba"), None);
+    }
+
+    #[test]
+    fn fuzzy_score_rewards_consecutive_runs() {
+        // Both match "tc" starting at index 0, but only the second keeps the match run
+        // unbroken, so it should score higher.
+        let broken = fuzzy_score("t9c", "tc").unwrap();
+        let unbroken = fuzzy_score("tc", "tc").unwrap();
+        assert!(unbroken > broken);
+    }
+
+    #[test]
+    fn fuzzy_score_rewards_word_boundary_matches() {
+        // Both contain "widget" as a subsequence, but only the second starts it right after a
+        // separator, so it should score higher.
+        let mid_word = fuzzy_score("gadgetwidget", "widget").unwrap();
+        let at_boundary = fuzzy_score("gadget widget", "widget").unwrap();
+        assert!(at_boundary > mid_word);
+    }
+
+    #[test]
+    fn fuzzy_score_matches_empty_query() {
+        assert_eq!(fuzzy_score("anything", ""), Some(0));
     }
 }
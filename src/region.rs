@@ -0,0 +1,109 @@
+use crate::capture::NormalizedRect;
+use iced::widget::canvas::{self, Canvas};
+use iced::{mouse, Color, Element, Length, Point, Rectangle, Renderer, Size, Theme};
+
+/// Emitted while the operator drags a selection rectangle across the [`Picker`] canvas.
+#[derive(Debug, Clone, Copy)]
+pub enum Event {
+    Changed(NormalizedRect),
+}
+
+/// Canvas program that lets the operator drag out the sub-rectangle to crop captures to. The
+/// rectangle is tracked in normalized (0.0-1.0) coordinates so it's independent of the canvas's
+/// on-screen size.
+#[derive(Default)]
+pub struct Picker {
+    pub rect: Option<NormalizedRect>,
+}
+
+#[derive(Default, Clone, Copy)]
+pub struct DragState {
+    start: Option<Point>,
+}
+
+impl canvas::Program<Event, Theme> for Picker {
+    type State = DragState;
+
+    fn update(
+        &self,
+        state: &mut DragState,
+        event: canvas::Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+    ) -> (canvas::event::Status, Option<Event>) {
+        let Some(position) = cursor.position_in(bounds) else {
+            return (canvas::event::Status::Ignored, None);
+        };
+
+        match event {
+            canvas::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+                state.start = Some(position);
+                (canvas::event::Status::Captured, None)
+            }
+            canvas::Event::Mouse(mouse::Event::CursorMoved { .. }) => match state.start {
+                Some(start) => (
+                    canvas::event::Status::Captured,
+                    Some(Event::Changed(normalized_rect(start, position, bounds.size()))),
+                ),
+                None => (canvas::event::Status::Ignored, None),
+            },
+            canvas::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                match state.start.take() {
+                    Some(start) => (
+                        canvas::event::Status::Captured,
+                        Some(Event::Changed(normalized_rect(start, position, bounds.size()))),
+                    ),
+                    None => (canvas::event::Status::Ignored, None),
+                }
+            }
+            _ => (canvas::event::Status::Ignored, None),
+        }
+    }
+
+    fn draw(
+        &self,
+        _state: &DragState,
+        renderer: &Renderer,
+        _theme: &Theme,
+        bounds: Rectangle,
+        _cursor: mouse::Cursor,
+    ) -> Vec<canvas::Geometry> {
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+        frame.fill_rectangle(Point::ORIGIN, bounds.size(), Color::from_rgb8(25, 25, 32));
+
+        if let Some(rect) = self.rect {
+            let top_left = Point::new(rect.x * bounds.width, rect.y * bounds.height);
+            let size = Size::new(rect.width * bounds.width, rect.height * bounds.height);
+
+            frame.fill_rectangle(top_left, size, Color::from_rgba8(80, 160, 255, 0.35));
+            frame.stroke(
+                &canvas::Path::rectangle(top_left, size),
+                canvas::Stroke::default().with_color(Color::from_rgb8(80, 160, 255)),
+            );
+        }
+
+        vec![frame.into_geometry()]
+    }
+}
+
+fn normalized_rect(start: Point, end: Point, bounds: Size) -> NormalizedRect {
+    let x0 = start.x.min(end.x).clamp(0.0, bounds.width);
+    let y0 = start.y.min(end.y).clamp(0.0, bounds.height);
+    let x1 = start.x.max(end.x).clamp(0.0, bounds.width);
+    let y1 = start.y.max(end.y).clamp(0.0, bounds.height);
+
+    NormalizedRect {
+        x: x0 / bounds.width,
+        y: y0 / bounds.height,
+        width: (x1 - x0).max(1.0) / bounds.width,
+        height: (y1 - y0).max(1.0) / bounds.height,
+    }
+}
+
+pub fn view(picker: &Picker) -> Element<'_, Event> {
+    Canvas::new(picker)
+        .width(Length::Fill)
+        .height(Length::Fixed(160.0))
+        .into()
+}
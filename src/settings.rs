@@ -0,0 +1,171 @@
+use futures::stream::{self, Stream};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::{Mutex, mpsc};
+
+/// Persisted as JSON in the platform config dir. Precedence, lowest to highest:
+/// [`Settings::default`], then the on-disk file, then environment variable overrides.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub capture_delay_ms: u64,
+    pub output_dir: Option<PathBuf>,
+    pub image_format: ImageFormat,
+    pub filename_template: String,
+    pub check_for_updates: bool,
+    pub dismissed_update_version: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Bmp,
+}
+
+impl ImageFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Png => "png",
+            ImageFormat::Jpeg => "jpg",
+            ImageFormat::Bmp => "bmp",
+        }
+    }
+
+    pub const ALL: [ImageFormat; 3] = [ImageFormat::Png, ImageFormat::Jpeg, ImageFormat::Bmp];
+}
+
+impl std::fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.extension().to_uppercase())
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            capture_delay_ms: 1500,
+            output_dir: None,
+            image_format: ImageFormat::Png,
+            filename_template: String::from("{code}"),
+            check_for_updates: true,
+            dismissed_update_version: None,
+        }
+    }
+}
+
+impl Settings {
+    /// Never fails: a missing or malformed config file just falls back to the defaults.
+    pub fn load() -> Self {
+        let mut value = serde_json::to_value(Settings::default()).unwrap_or_default();
+
+        if let Some(path) = Self::config_path() {
+            if let Ok(contents) = std::fs::read_to_string(&path) {
+                if let Ok(on_disk) = serde_json::from_str::<serde_json::Value>(&contents) {
+                    merge_json(&mut value, on_disk);
+                }
+            }
+        }
+
+        let mut settings: Settings = serde_json::from_value(value).unwrap_or_default();
+        settings.apply_env_overrides();
+        settings
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::config_path().ok_or("Could not determine config directory.")?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(&path, json).map_err(|e| e.to_string())
+    }
+
+    fn apply_env_overrides(&mut self) {
+        if let Ok(dir) = std::env::var("HYPERGRAB_OUTPUT_DIR") {
+            if !dir.is_empty() {
+                self.output_dir = Some(PathBuf::from(dir));
+            }
+        }
+        if let Ok(delay) = std::env::var("HYPERGRAB_CAPTURE_DELAY_MS") {
+            if let Ok(delay) = delay.parse() {
+                self.capture_delay_ms = delay;
+            }
+        }
+        if let Ok(template) = std::env::var("HYPERGRAB_FILENAME_TEMPLATE") {
+            if !template.is_empty() {
+                self.filename_template = template;
+            }
+        }
+    }
+
+    fn config_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("hypergrab").join("settings.json"))
+    }
+}
+
+pub type Sender = mpsc::UnboundedSender<Settings>;
+pub type SharedReceiver = Arc<Mutex<mpsc::UnboundedReceiver<Settings>>>;
+
+pub fn channel() -> (Sender, SharedReceiver) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    (tx, Arc::new(Mutex::new(rx)))
+}
+
+/// Drains queued settings one at a time and saves each in turn, so a burst of changes (e.g. one
+/// per keystroke) can't race and leave an earlier save finishing after a later one.
+pub fn stream(receiver: SharedReceiver) -> impl Stream<Item = Result<(), String>> {
+    stream::unfold(receiver, |receiver| async move {
+        let settings = receiver.lock().await.recv().await;
+        settings.map(|settings| (settings.save(), receiver))
+    })
+}
+
+/// Recursively merges `overlay` into `base`, in place. Keys `overlay` doesn't mention are left
+/// untouched in `base`.
+fn merge_json(base: &mut serde_json::Value, overlay: serde_json::Value) {
+    match (base, overlay) {
+        (serde_json::Value::Object(base_map), serde_json::Value::Object(overlay_map)) => {
+            for (key, overlay_value) in overlay_map {
+                merge_json(base_map.entry(key).or_insert(serde_json::Value::Null), overlay_value);
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn merge_json_overlay_overrides_matching_keys() {
+        let mut base = json!({"a": 1, "b": 2});
+        merge_json(&mut base, json!({"b": 20}));
+        assert_eq!(base, json!({"a": 1, "b": 20}));
+    }
+
+    #[test]
+    fn merge_json_preserves_base_keys_absent_from_overlay() {
+        let mut base = json!({"a": 1, "b": 2});
+        merge_json(&mut base, json!({"b": 20}));
+        assert_eq!(base["a"], json!(1));
+    }
+
+    #[test]
+    fn merge_json_merges_nested_objects_recursively() {
+        let mut base = json!({"outer": {"x": 1, "y": 2}});
+        merge_json(&mut base, json!({"outer": {"y": 20}}));
+        assert_eq!(base, json!({"outer": {"x": 1, "y": 20}}));
+    }
+
+    #[test]
+    fn merge_json_overlay_value_replaces_non_object_base() {
+        let mut base = json!({"a": [1, 2, 3]});
+        merge_json(&mut base, json!({"a": "replaced"}));
+        assert_eq!(base, json!({"a": "replaced"}));
+    }
+}
@@ -0,0 +1,74 @@
+use serde::Deserialize;
+
+pub const CURRENT_VERSION: &str = "0.99.0-STABLE";
+
+const REPO: &str = "danico-oss/hypergrab";
+
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    html_url: String,
+}
+
+/// Any network or parsing failure is treated as "no update" rather than surfaced as an error —
+/// this is a best-effort background check, not something that should interrupt the operator.
+pub async fn check_for_update() -> Option<(String, String)> {
+    let url = format!("https://api.github.com/repos/{}/releases/latest", REPO);
+    let response = reqwest::Client::new()
+        .get(url)
+        .header("User-Agent", "hypergrab-update-check")
+        .send()
+        .await
+        .ok()?;
+
+    let release: Release = response.json().await.ok()?;
+    let latest = release.tag_name.trim_start_matches('v');
+
+    is_newer(latest, CURRENT_VERSION).then(|| (latest.to_string(), release.html_url))
+}
+
+/// Compares dotted numeric version prefixes, ignoring any trailing `-suffix`.
+fn is_newer(candidate: &str, current: &str) -> bool {
+    parse_numeric(candidate) > parse_numeric(current)
+}
+
+fn parse_numeric(version: &str) -> Vec<u64> {
+    version
+        .split('-')
+        .next()
+        .unwrap_or(version)
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_newer_compares_numerically_not_lexicographically() {
+        assert!(is_newer("0.100.0", "0.99.0-STABLE"));
+        assert!(!is_newer("0.99.0-STABLE", "0.100.0"));
+    }
+
+    #[test]
+    fn is_newer_ignores_suffix_on_both_sides() {
+        assert!(!is_newer("1.2.0-BETA", "1.2.0-STABLE"));
+    }
+
+    #[test]
+    fn is_newer_false_when_equal() {
+        assert!(!is_newer("0.99.0", "0.99.0"));
+    }
+
+    #[test]
+    fn parse_numeric_drops_suffix_and_parses_each_part() {
+        assert_eq!(parse_numeric("0.99.0-STABLE"), vec![0, 99, 0]);
+    }
+
+    #[test]
+    fn parse_numeric_defaults_unparseable_part_to_zero() {
+        assert_eq!(parse_numeric("1.x.0"), vec![1, 0, 0]);
+    }
+}